@@ -59,9 +59,48 @@ pub(crate) struct FieldParams {
     #[darling(with = "parse_optional_expression", map = "Some")]
     pub(crate) default: Option<SpannedValue<Option<syn::Expr>>>,
 
+    /// A custom conversion expression evaluated to produce the member's value.
+    /// May use `?` to fail the conversion; doing so requires the top-level
+    /// `#[builder(finish_fn(error = ...))]` to be configured since it turns
+    /// the generated `finish_fn` into one that returns a `Result`.
+    #[darling(with = "parse_required_expression", map = "Some")]
+    pub(crate) with: Option<SpannedValue<syn::Expr>>,
+
     /// Makes the field required no matter what default treatment for such field
     /// is applied.
     pub(crate) required: Option<Flag>,
+
+    /// Turns this member into a private accumulator with a custom storage
+    /// type and build expression, rather than a setter-driven member.
+    pub(crate) field: Option<FieldAccumulatorParams>,
+
+    /// Overrides for the generated setter method(s).
+    pub(crate) setter: Option<SetterParams>,
+
+    /// Additionally generates a setter with this name that accepts the full
+    /// `Option<T>` as-is, for `Option<T>` members whose regular setter strips
+    /// the `Option` and takes `T` directly.
+    pub(crate) into_fallback: Option<syn::Ident>,
+
+    /// Additionally generates a zero-argument setter with this name that sets
+    /// the member to `true` when called. Only valid on `bool` members.
+    pub(crate) flag_fallback: Option<syn::Ident>,
+}
+
+/// Configuration for `#[builder(field(...))]`.
+#[derive(Debug, darling::FromMeta)]
+pub(crate) struct FieldAccumulatorParams {
+    #[darling(rename = "type")]
+    pub(crate) ty: syn::Type,
+
+    pub(crate) build: syn::Expr,
+}
+
+/// Configuration for `#[builder(setter(...))]`.
+#[derive(Debug, darling::FromMeta)]
+pub(crate) struct SetterParams {
+    /// Overrides the doc comment inherited from the member on the setter method(s).
+    pub(crate) doc: Option<String>,
 }
 
 /// This primitive represents the syntax that accepts only two states:
@@ -99,6 +138,38 @@ fn parse_optional_expression(meta: &syn::Meta) -> Result<SpannedValue<Option<syn
     }
 }
 
+fn parse_required_expression(meta: &syn::Meta) -> Result<SpannedValue<syn::Expr>> {
+    match meta {
+        syn::Meta::Path(_) => Err(Error::unsupported_format("path").with_span(meta)),
+        syn::Meta::List(_) => Err(Error::unsupported_format("list").with_span(meta)),
+        syn::Meta::NameValue(nv) => Ok(SpannedValue::new(nv.value.clone(), nv.span())),
+    }
+}
+
+/// Detects whether `expr` contains a `?` operator anywhere in its syntax tree,
+/// which makes the expression fallible and, in turn, the `finish_fn` that
+/// evaluates it fallible too. A `?` nested inside a closure or `async` block
+/// is scoped to that inner body, not to `expr` itself, so those aren't visited.
+fn contains_try_expr(expr: &syn::Expr) -> bool {
+    use syn::visit::Visit;
+
+    struct TryVisitor(bool);
+
+    impl Visit<'_> for TryVisitor {
+        fn visit_expr_try(&mut self, _: &syn::ExprTry) {
+            self.0 = true;
+        }
+
+        fn visit_expr_closure(&mut self, _: &syn::ExprClosure) {}
+
+        fn visit_expr_async(&mut self, _: &syn::ExprAsync) {}
+    }
+
+    let mut visitor = TryVisitor(false);
+    visitor.visit_expr(expr);
+    visitor.0
+}
+
 impl Field {
     pub(crate) fn new(
         origin: FieldOrigin,
@@ -143,6 +214,53 @@ impl Field {
                     so explicit #[builder(default)] is redundant",
                 );
             }
+
+            if self.params.with.is_some() {
+                prox::bail!(
+                    &default.span(),
+                    "#[builder(default = ...)] and #[builder(with = ...)] are mutually \
+                    exclusive: `with` already fully determines the member's value, \
+                    leaving no room for a separate default",
+                );
+            }
+        }
+
+        if let Some(with) = &self.params.with {
+            if contains_try_expr(with) {
+                let ty = if self.ty.is_option() {
+                    Some("Option")
+                } else if self.ty.is_bool() {
+                    Some("bool")
+                } else {
+                    None
+                };
+
+                if let Some(ty) = ty {
+                    prox::bail!(
+                        &with.span(),
+                        "type `{ty}` already has an unambiguous default value, \
+                        so a fallible #[builder(with = ...)] expression is redundant",
+                    );
+                }
+            }
+
+            if self.params.into_fallback.is_some() {
+                prox::bail!(
+                    &with.span(),
+                    "#[builder(with = ...)] and #[builder(into_fallback)] are mutually \
+                    exclusive: a `with`-configured member has no regular setter for \
+                    `into_fallback` to fall back from",
+                );
+            }
+
+            if self.params.flag_fallback.is_some() {
+                prox::bail!(
+                    &with.span(),
+                    "#[builder(with = ...)] and #[builder(flag_fallback)] are mutually \
+                    exclusive: a `with`-configured member has no regular setter for \
+                    `flag_fallback` to fall back from",
+                );
+            }
         }
 
         if let Some(required) = &self.params.required {
@@ -171,9 +289,114 @@ impl Field {
             }
         }
 
+        if let Some(into_fallback) = &self.params.into_fallback {
+            if self.ty.option_type_param().is_none() {
+                prox::bail!(
+                    &into_fallback.span(),
+                    "#[builder(into_fallback)] can only be used on `Option<T>` members \
+                    since it's meant to accept the `Option<T>` that the regular setter \
+                    would otherwise strip",
+                );
+            }
+        }
+
+        if let Some(flag_fallback) = &self.params.flag_fallback {
+            if !self.ty.is_bool() {
+                prox::bail!(
+                    &flag_fallback.span(),
+                    "#[builder(flag_fallback)] can only be applied to `bool`. All other \
+                    types don't have an unambiguous \"flag is set\" value to default to",
+                );
+            }
+        }
+
+        if let Some(field) = &self.params.field {
+            if self.params.default.is_some() {
+                prox::bail!(
+                    &field.ty.span(),
+                    "#[builder(field(...))] and #[builder(default)] are mutually \
+                    exclusive: an accumulator member isn't derived from the input \
+                    at all, so it has no default to compute",
+                );
+            }
+
+            if self.params.required.is_some() {
+                prox::bail!(
+                    &field.ty.span(),
+                    "#[builder(field(...))] and #[builder(required)] are mutually \
+                    exclusive: an accumulator member is always considered set",
+                );
+            }
+
+            if self.params.with.is_some() {
+                prox::bail!(
+                    &field.ty.span(),
+                    "#[builder(field(...))] and #[builder(with = ...)] are mutually \
+                    exclusive: both fully determine the member's value, and the \
+                    accumulator's `build` expression takes priority, so `with` \
+                    would never run",
+                );
+            }
+
+            if self.params.into_fallback.is_some() {
+                prox::bail!(
+                    &field.ty.span(),
+                    "#[builder(field(...))] and #[builder(into_fallback)] are mutually \
+                    exclusive: an accumulator member has no regular setter for \
+                    `into_fallback` to fall back from",
+                );
+            }
+
+            if self.params.flag_fallback.is_some() {
+                prox::bail!(
+                    &field.ty.span(),
+                    "#[builder(field(...))] and #[builder(flag_fallback)] are mutually \
+                    exclusive: an accumulator member has no regular setter for \
+                    `flag_fallback` to fall back from",
+                );
+            }
+        }
+
         Ok(())
     }
 
+    /// Doc comment(s) for the generated setter method(s): the `setter(doc = ...)`
+    /// override if present, otherwise the doc comment inherited from the member.
+    pub(crate) fn setter_docs(&self) -> Vec<syn::Attribute> {
+        let doc = match self.params.setter.as_ref().and_then(|setter| setter.doc.as_deref()) {
+            Some(doc) => doc,
+            None => return self.docs.clone(),
+        };
+
+        vec![syn::parse_quote!(#[doc = #doc])]
+    }
+
+    /// Generates the primary setter method for this member, taking the
+    /// stripped [`Self::as_optional`] type (or the member's own type if it's
+    /// required) and documented with [`Self::setter_docs`].
+    pub(crate) fn primary_setter_fn(&self) -> TokenStream2 {
+        let ident = &self.ident;
+        let docs = self.setter_docs();
+        let ty = &self.ty;
+        let param_ty = self.as_optional().unwrap_or(ty);
+
+        // For members whose `Set<_>` state is `Option<T>` (see `set_state_type_param`),
+        // the stripped setter param must be re-wrapped to match that representation.
+        let value = if self.as_optional().is_some() {
+            quote!(::core::option::Option::Some(#ident))
+        } else {
+            quote!(#ident)
+        };
+
+        quote! {
+            #(#docs)*
+            pub fn #ident(mut self, #ident: #param_ty) -> Self {
+                self.#ident = ::core::option::Option::Some(#value);
+                self
+            }
+        }
+    }
+
     pub(crate) fn as_optional(&self) -> Option<&syn::Type> {
         // User override takes the wheel entirely
         if self.params.required.is_some() {
@@ -185,9 +408,151 @@ impl Field {
             .or_else(|| (self.ty.is_bool() || self.params.default.is_some()).then_some(&self.ty))
     }
 
+    /// Name of the extra setter declared via `#[builder(into_fallback = name)]`, if any.
+    pub(crate) fn into_fallback_setter_ident(&self) -> Option<&syn::Ident> {
+        self.params.into_fallback.as_ref()
+    }
+
+    /// Name of the extra setter declared via `#[builder(flag_fallback = name)]`, if any.
+    pub(crate) fn flag_fallback_setter_ident(&self) -> Option<&syn::Ident> {
+        self.params.flag_fallback.as_ref()
+    }
+
+    /// Generates the extra setter that accepts `self.ty`'s `Option<T>` as-is,
+    /// for `#[builder(into_fallback = name)]`.
+    pub(crate) fn into_fallback_setter_fn(&self) -> Option<TokenStream2> {
+        let option_ty = self.ty.option_type_param()?;
+        let setter_ident = self.into_fallback_setter_ident()?;
+        let ident = &self.ident;
+
+        // `#ident` is already `Option<#option_ty>`, i.e. already shaped like
+        // `primary_setter_fn`'s inner `Some(_)`, so only the outer "set" wrap
+        // is needed here to match that same `Set<_>` representation.
+        Some(quote! {
+            pub fn #setter_ident(mut self, #ident: ::core::option::Option<#option_ty>) -> Self {
+                self.#ident = ::core::option::Option::Some(#ident);
+                self
+            }
+        })
+    }
+
+    /// Generates the extra zero-argument setter that sets this `bool` member
+    /// to `true`, for `#[builder(flag_fallback = name)]`.
+    pub(crate) fn flag_fallback_setter_fn(&self) -> Option<TokenStream2> {
+        let setter_ident = self.flag_fallback_setter_ident()?;
+        let ident = &self.ident;
+
+        // Matches `primary_setter_fn`'s representation: `Some(Some(_))` when the
+        // `bool` is optional (the inner `Some` is the stripped value, the outer
+        // marks "set"), or just `Some(_)` when it's `#[builder(required)]`.
+        let value = if self.as_optional().is_some() {
+            quote!(::core::option::Option::Some(true))
+        } else {
+            quote!(true)
+        };
+
+        Some(quote! {
+            pub fn #setter_ident(mut self) -> Self {
+                self.#ident = ::core::option::Option::Some(#value);
+                self
+            }
+        })
+    }
+
+    /// Whether this member's value is fully determined by a `field(build = ...)`
+    /// or `with = ...` expression, with no setter accepting input for it.
+    pub(crate) fn has_no_setter(&self) -> bool {
+        self.no_setter_state_type().is_some()
+    }
+
+    /// The `Set<_>` inner type for a member with [`Self::has_no_setter`]: the
+    /// accumulator's storage type for `field(...)`, or the member's own type
+    /// for `with`.
+    fn no_setter_state_type(&self) -> Option<&syn::Type> {
+        self.accumulator_field_type()
+            .or_else(|| self.params.with.as_ref().map(|_| self.ty.as_ref()))
+    }
+
+    /// All setter methods generated for this member: the primary setter plus
+    /// any `into_fallback`/`flag_fallback` extras. Members with
+    /// [`Self::has_no_setter`] get none: their value comes entirely from
+    /// their `field(build = ...)`/`with = ...` expression instead.
+    pub(crate) fn setter_fns(&self) -> Vec<TokenStream2> {
+        if self.has_no_setter() {
+            return Vec::new();
+        }
+
+        let mut setters = vec![self.primary_setter_fn()];
+        setters.extend(self.into_fallback_setter_fn());
+        setters.extend(self.flag_fallback_setter_fn());
+        setters
+    }
+
+    /// Whether this member's value is computed via a fallible `default`,
+    /// `with`, or accumulator `build` expression (one using `?`).
+    pub(crate) fn is_fallible(&self) -> bool {
+        let default_is_fallible = self
+            .params
+            .default
+            .as_ref()
+            .and_then(|default| (**default).as_ref())
+            .is_some_and(contains_try_expr);
+
+        let with_is_fallible = self
+            .params
+            .with
+            .as_ref()
+            .is_some_and(|with| contains_try_expr(with));
+
+        let accumulator_build_is_fallible = self
+            .params
+            .field
+            .as_ref()
+            .is_some_and(|field| contains_try_expr(&field.build));
+
+        default_is_fallible || with_is_fallible || accumulator_build_is_fallible
+    }
+
+    /// Expression that computes this member's final value inside `finish_fn`,
+    /// in priority order: accumulator `build` > `with` > `default`. `None`
+    /// means the member's value is just whatever the setter stored.
+    pub(crate) fn finish_value_expr(&self) -> Option<TokenStream2> {
+        if let Some(field) = &self.params.field {
+            return Some(Self::wrap_fallible(&field.build));
+        }
+
+        if let Some(with) = &self.params.with {
+            return Some(Self::wrap_fallible(with));
+        }
+
+        let expr = (**self.params.default.as_ref()?).as_ref()?;
+        Some(Self::wrap_fallible(expr))
+    }
+
+    /// Wraps `expr` so that a `?` inside it short-circuits `finish_fn` rather
+    /// than failing to compile outside of a function returning `Result`.
+    /// Expressions without a `?` pass through unchanged.
+    fn wrap_fallible(expr: &syn::Expr) -> TokenStream2 {
+        if contains_try_expr(expr) {
+            quote!((|| -> ::core::result::Result<_, _> { ::core::result::Result::Ok(#expr) })()?)
+        } else {
+            quote!(#expr)
+        }
+    }
+
+    /// Storage type of the private accumulator declared via `#[builder(field(type = ...))]`.
+    pub(crate) fn accumulator_field_type(&self) -> Option<&syn::Type> {
+        self.params.field.as_ref().map(|field| &field.ty)
+    }
+
     pub(crate) fn unset_state_type(&self) -> TokenStream2 {
         let ty = &self.ty;
 
+        // Members with no setter (accumulators, `with`) are always `Set`.
+        if let Some(no_setter_ty) = self.no_setter_state_type() {
+            return quote!(bon::private::Set<#no_setter_ty>);
+        }
+
         if let Some(inner_type) = self.as_optional() {
             quote!(bon::private::Optional<#inner_type>)
         } else {
@@ -198,6 +563,12 @@ impl Field {
     pub(crate) fn set_state_type_param(&self) -> TokenStream2 {
         let ty = &self.ty;
 
+        // Keep this in sync with `unset_state_type`: the inner type of the
+        // `Set<_>` state must match the `Set<_>` already returned there.
+        if let Some(no_setter_ty) = self.no_setter_state_type() {
+            return quote!(#no_setter_ty);
+        }
+
         self.as_optional()
             .map(|ty| quote!(Option<#ty>))
             .unwrap_or_else(|| quote!(#ty))
@@ -208,4 +579,21 @@ impl Field {
 
         quote!(bon::private::Set<#ty>)
     }
+
+    /// Declares the private accumulator field on the builder struct, e.g.
+    /// `xs: Vec<u32>`.
+    pub(crate) fn accumulator_field_decl(&self) -> Option<TokenStream2> {
+        let ident = &self.ident;
+        let ty = self.accumulator_field_type()?;
+
+        Some(quote!(#ident: #ty))
+    }
+
+    /// Initializes the private accumulator field in the builder's starting state.
+    pub(crate) fn accumulator_field_init(&self) -> Option<TokenStream2> {
+        self.accumulator_field_type()?;
+        let ident = &self.ident;
+
+        Some(quote!(#ident: ::core::default::Default::default()))
+    }
 }
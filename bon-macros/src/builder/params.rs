@@ -1,13 +1,89 @@
+use super::builder_gen::field::Field;
 use darling::FromMeta;
 use prox::prelude::*;
 use quote::quote;
+use syn::spanned::Spanned;
 
 #[derive(Debug, FromMeta)]
 pub(crate) struct BuilderParams {
-    pub(crate) finish_fn: Option<syn::Ident>,
+    pub(crate) finish_fn: Option<FinishFnParams>,
     pub(crate) builder_type: Option<syn::Ident>,
 }
 
+impl BuilderParams {
+    /// Cross-checks `finish_fn(error = ...)` against the fields' fallible
+    /// `default`/`with`/accumulator `build` expressions. This can't be done
+    /// in `Field::validate` since it needs visibility into both structs.
+    pub(crate) fn validate_finish_fn_fallibility(&self, fields: &[Field]) -> Result {
+        let error_ty = self.finish_fn.as_ref().and_then(|finish_fn| finish_fn.error.as_ref());
+        let fallible_field = fields.iter().find(|field| field.is_fallible());
+
+        if let (None, Some(field)) = (error_ty, fallible_field) {
+            let ident = &field.ident;
+            prox::bail!(
+                &ident.span(),
+                "`{ident}` uses a fallible `?` expression, but no \
+                #[builder(finish_fn(error = ...))] was configured to declare \
+                the error type `finish_fn` should return",
+            );
+        }
+
+        if let (Some(error_ty), None) = (error_ty, fallible_field) {
+            prox::bail!(
+                &error_ty.span(),
+                "#[builder(finish_fn(error = ...))] was configured, but no member \
+                uses a fallible `default`/`with`/`field(build = ...)` expression \
+                that could return it",
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Configuration for `#[builder(finish_fn(...))]`. Accepts either a bare
+/// name override (`finish_fn = my_finish`), matching the shorthand every
+/// other renaming attribute in this crate supports, or the full form that
+/// additionally lets the finishing function be made fallible.
+#[derive(Debug, Default)]
+pub(crate) struct FinishFnParams {
+    pub(crate) name: Option<syn::Ident>,
+
+    /// The error type returned by the generated `finish_fn` when it's made
+    /// fallible by one or more members using a fallible `#[builder(default)]`,
+    /// `#[builder(with)]` or `#[builder(field(build))]` expression (i.e. one
+    /// that uses `?`).
+    pub(crate) error: Option<syn::Type>,
+}
+
+impl FromMeta for FinishFnParams {
+    fn from_meta(meta: &syn::Meta) -> Result<Self> {
+        if let syn::Meta::NameValue(meta) = meta {
+            let val = &meta.value;
+            let ident = syn::parse2(quote!(#val))?;
+
+            return Ok(Self {
+                name: Some(ident),
+                error: None,
+            });
+        }
+
+        #[derive(Debug, FromMeta)]
+        struct Full {
+            name: Option<syn::Ident>,
+            error: Option<syn::Type>,
+        }
+
+        let full = Full::from_meta(meta)?;
+        let me = Self {
+            name: full.name,
+            error: full.error,
+        };
+
+        Ok(me)
+    }
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct ItemParams {
     pub(crate) name: Option<syn::Ident>,